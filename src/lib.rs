@@ -3,19 +3,71 @@ use std::ffi::{c_void, CString};
 use std::fmt::Debug;
 use std::sync::{Mutex, MutexGuard};
 
-use cgns_sys::DataType_t::RealDouble;
-use cgns_sys::ZoneType_t::Unstructured;
 use cgns_sys::{
-    cg_array_write, cg_base_write, cg_biter_read, cg_biter_write, cg_close, cg_coord_info,
-    cg_coord_read, cg_coord_write, cg_elements_read, cg_get_error, cg_golist, cg_open,
-    cg_section_read, cg_section_write, cg_ziter_write, cg_zone_read, cg_zone_write, DataType_t,
-    CG_MODE_MODIFY, CG_MODE_READ, CG_MODE_WRITE,
+    cg_array_info, cg_array_read_as, cg_array_write, cg_base_read, cg_base_write, cg_biter_read,
+    cg_biter_write, cg_close, cg_coord_info, cg_coord_read, cg_coord_write, cg_elements_read,
+    cg_field_info, cg_field_read, cg_field_write, cg_get_error, cg_golist, cg_narrays, cg_nsols,
+    cg_open, cg_section_read, cg_section_write, cg_sol_info, cg_sol_write, cg_ziter_write,
+    cg_zone_read, cg_zone_type, cg_zone_write, DataType_t, CG_MODE_MODIFY, CG_MODE_READ,
+    CG_MODE_WRITE, CG_NODE_NOT_FOUND,
 };
 
 pub use cgns_sys::ElementType_t;
-pub struct Error(i32);
+pub use cgns_sys::GridLocation_t;
+pub use cgns_sys::ZoneType_t;
+
+mod tree;
+pub use tree::{Node, NodeData};
+
+/// A CGNS Mid-Level Library failure, classified from the return code of the
+/// call that raised it plus the `cg_get_error()` text captured at that
+/// moment (the global error string is overwritten by the next CGNS call, so
+/// it must be read immediately rather than lazily in `Debug`).
+pub enum Error {
+    /// The requested node does not exist at the current path.
+    NodeNotFound { message: String },
+    /// `cg_goto`/`cg_golist` was given a path that does not resolve.
+    IncorrectPath { message: String },
+    /// The operation needs an open file but none is open (e.g. a stale or
+    /// already-closed file index).
+    FileNotOpen { message: String },
+    /// The underlying HDF5/ADF storage layer reported an I/O failure.
+    Io { message: String },
+    /// A `&str` passed to a CGNS call contained an interior NUL and could
+    /// not be turned into a `CString`.
+    InvalidName,
+    /// Any other CGNS error, kept as the raw return code plus message.
+    Cgns { code: i32, message: String },
+}
+
 type Result<T> = std::result::Result<T, Error>;
 
+fn cstring(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|_| Error::InvalidName)
+}
+
+fn last_error_message() -> String {
+    let msg = unsafe { CStr::from_ptr(cg_get_error()) };
+    msg.to_string_lossy().into_owned()
+}
+
+fn classify(code: i32, message: String) -> Error {
+    if code == CG_NODE_NOT_FOUND as i32 {
+        Error::NodeNotFound { message }
+    } else if code == cgns_sys::CG_INCORRECT_PATH as i32 {
+        Error::IncorrectPath { message }
+    } else {
+        let lower = message.to_lowercase();
+        if lower.contains("not open") {
+            Error::FileNotOpen { message }
+        } else if lower.contains("i/o") || lower.contains("hdf5") {
+            Error::Io { message }
+        } else {
+            Error::Cgns { code, message }
+        }
+    }
+}
+
 pub enum Mode {
     Read,
     Write,
@@ -26,6 +78,84 @@ pub trait CgnsDataType {
     const SYS: DataType_t::Type;
 }
 
+// `cg_array_write`/`cg_narrays`/`cg_array_info`/`cg_array_read` all operate on
+// whatever node `cg_goto`/`cg_golist` last positioned at rather than taking a
+// file/base/zone argument, so they are only ever safe to call while holding
+// the `CGNS_MUTEX` that guarantees nothing else re-positions in between.
+// These free functions hold that invariant in one place; `GotoContext`, which
+// already holds the guard, calls them directly, and `File`'s one-shot
+// wrappers below acquire a `GotoContext` (and so the guard) before calling
+// them too.
+fn array_write_at<T: CgnsDataType>(arrayname: &str, dimensions: &[i32], data: &[T]) -> Result<()> {
+    let arrayname = cstring(arrayname)?;
+    assert_eq!(
+        dimensions.iter().copied().reduce(|a, v| a * v).unwrap(),
+        data.len() as i32
+    );
+    let e = unsafe {
+        cg_array_write(
+            arrayname.as_ptr(),
+            T::SYS,
+            dimensions.len() as i32,
+            dimensions.as_ptr(),
+            data.as_ptr().cast::<std::ffi::c_void>(),
+        )
+    };
+    if e == 0 {
+        Ok(())
+    } else {
+        Err(e.into())
+    }
+}
+
+fn narrays_at() -> Result<i32> {
+    let mut n = 0;
+    let e = unsafe { cg_narrays(&mut n) };
+    if e == 0 {
+        Ok(n)
+    } else {
+        Err(e.into())
+    }
+}
+
+fn array_info_at(a: i32) -> Result<(String, DataType_t::Type, Vec<i32>)> {
+    let mut raw_name = [0_u8; 33];
+    let mut datatype = DataType_t::Integer;
+    let mut ndim: i32 = 0;
+    let mut raw_dims = [0_i32; 12];
+    let e = unsafe {
+        cg_array_info(
+            a,
+            raw_name.as_mut_ptr().cast(),
+            &mut datatype,
+            &mut ndim,
+            raw_dims.as_mut_ptr(),
+        )
+    };
+    if e == 0 {
+        Ok((
+            raw_to_string(&raw_name),
+            datatype,
+            raw_dims[0..ndim as usize].to_vec(),
+        ))
+    } else {
+        Err(e.into())
+    }
+}
+
+fn array_read_at<T: CgnsDataType>(a: i32, data: &mut [T]) -> Result<()> {
+    let e = unsafe { cg_array_read_as(a, T::SYS, data.as_mut_ptr().cast::<c_void>()) };
+    if e == 0 {
+        Ok(())
+    } else {
+        Err(e.into())
+    }
+}
+
+/// A navigation scope obtained from [`File::golist`]: it holds the
+/// `CGNS_MUTEX` for as long as it is alive, so a caller can navigate once
+/// and then perform any number of node-local reads/writes at that position
+/// without another thread repositioning `cg_goto` in between.
 pub struct GotoContext<'a>(MutexGuard<'a, ()>);
 
 impl<'a> GotoContext<'a> {
@@ -35,25 +165,19 @@ impl<'a> GotoContext<'a> {
         dimensions: &[i32],
         data: &[T],
     ) -> Result<()> {
-        let arrayname = CString::new(arrayname).unwrap();
-        assert_eq!(
-            dimensions.iter().copied().reduce(|a, v| a * v).unwrap(),
-            data.len() as i32
-        );
-        let e = unsafe {
-            cg_array_write(
-                arrayname.as_ptr(),
-                T::SYS,
-                dimensions.len() as i32,
-                dimensions.as_ptr(),
-                data.as_ptr().cast::<std::ffi::c_void>(),
-            )
-        };
-        if e == 0 {
-            Ok(())
-        } else {
-            Err(e.into())
-        }
+        array_write_at(arrayname, dimensions, data)
+    }
+
+    pub fn narrays(&self) -> Result<i32> {
+        narrays_at()
+    }
+
+    pub fn array_info(&self, a: i32) -> Result<(String, DataType_t::Type, Vec<i32>)> {
+        array_info_at(a)
+    }
+
+    pub fn array_read<T: CgnsDataType>(&self, a: i32, data: &mut [T]) -> Result<()> {
+        array_read_at(a, data)
     }
 }
 
@@ -61,6 +185,18 @@ impl CgnsDataType for i32 {
     const SYS: DataType_t::Type = DataType_t::Integer;
 }
 
+impl CgnsDataType for i64 {
+    const SYS: DataType_t::Type = DataType_t::LongInteger;
+}
+
+impl CgnsDataType for f32 {
+    const SYS: DataType_t::Type = DataType_t::RealSingle;
+}
+
+impl CgnsDataType for f64 {
+    const SYS: DataType_t::Type = DataType_t::RealDouble;
+}
+
 impl From<Mode> for i32 {
     fn from(m: Mode) -> i32 {
         match m {
@@ -73,23 +209,37 @@ impl From<Mode> for i32 {
 
 impl From<i32> for Error {
     fn from(code: i32) -> Self {
-        Error(code)
+        classify(code, last_error_message())
     }
 }
 
 impl Debug for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let msg = unsafe { CStr::from_ptr(cg_get_error()) };
-        write!(f, "{} (error {})", msg.to_str().unwrap(), self.0)
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NodeNotFound { message } => write!(f, "node not found: {message}"),
+            Error::IncorrectPath { message } => write!(f, "incorrect path: {message}"),
+            Error::FileNotOpen { message } => write!(f, "file not open: {message}"),
+            Error::Io { message } => write!(f, "I/O error: {message}"),
+            Error::InvalidName => write!(f, "name contains an interior NUL byte"),
+            Error::Cgns { code, message } => write!(f, "{message} (error {code})"),
+        }
     }
 }
 
+impl std::error::Error for Error {}
+
 static CGNS_MUTEX: Mutex<()> = Mutex::new(());
 
 pub fn open(path: &str, mode: Mode) -> Result<File> {
     let _l = CGNS_MUTEX.lock().unwrap();
     let mut fd: i32 = 0;
-    let path = CString::new(path).unwrap();
+    let path = cstring(path)?;
     let f = unsafe { cg_open(path.as_ptr(), mode.into(), &mut fd) };
     if f == 0 {
         Ok(File(fd))
@@ -141,6 +291,11 @@ impl SectionInfo {
     }
 }
 
+pub struct SolutionInfo {
+    pub name: String,
+    pub location: GridLocation_t::Type,
+}
+
 impl File {
     pub fn close(&mut self) -> Result<()> {
         let _l = CGNS_MUTEX.lock().unwrap();
@@ -154,7 +309,7 @@ impl File {
 
     pub fn biter_write(&mut self, base: Base, base_iter_name: &str, n_steps: i32) -> Result<()> {
         let _l = CGNS_MUTEX.lock().unwrap();
-        let base_iter_name = CString::new(base_iter_name).unwrap();
+        let base_iter_name = cstring(base_iter_name)?;
         let e = unsafe { cg_biter_write(self.0, base.0, base_iter_name.as_ptr(), n_steps) };
         if e == 0 {
             Ok(())
@@ -177,7 +332,10 @@ impl File {
 
     pub fn golist(&self, base: Base, labels: &[&str], index: &[i32]) -> Result<GotoContext> {
         let l = CGNS_MUTEX.lock().unwrap();
-        let labels: Vec<_> = labels.iter().map(|&s| CString::new(s).unwrap()).collect();
+        let labels: Vec<_> = labels
+            .iter()
+            .map(|&s| cstring(s))
+            .collect::<Result<_>>()?;
         let mut labels_ptr: Vec<_> = labels.iter().map(|s| s.as_ptr() as *mut i8).collect();
         let e = unsafe {
             cg_golist(
@@ -195,10 +353,53 @@ impl File {
         }
     }
 
+    /// One-shot equivalent of `golist(base, labels, index)?.array_write(..)`,
+    /// for callers that only need a single node-local write.
+    pub fn array_write<T: CgnsDataType>(
+        &mut self,
+        base: Base,
+        labels: &[&str],
+        index: &[i32],
+        arrayname: &str,
+        dimensions: &[i32],
+        data: &[T],
+    ) -> Result<()> {
+        self.golist(base, labels, index)?
+            .array_write(arrayname, dimensions, data)
+    }
+
+    /// One-shot equivalent of `golist(base, labels, index)?.narrays()`.
+    pub fn narrays(&self, base: Base, labels: &[&str], index: &[i32]) -> Result<i32> {
+        self.golist(base, labels, index)?.narrays()
+    }
+
+    /// One-shot equivalent of `golist(base, labels, index)?.array_info(a)`.
+    pub fn array_info(
+        &self,
+        base: Base,
+        labels: &[&str],
+        index: &[i32],
+        a: i32,
+    ) -> Result<(String, DataType_t::Type, Vec<i32>)> {
+        self.golist(base, labels, index)?.array_info(a)
+    }
+
+    /// One-shot equivalent of `golist(base, labels, index)?.array_read(a, data)`.
+    pub fn array_read<T: CgnsDataType>(
+        &self,
+        base: Base,
+        labels: &[&str],
+        index: &[i32],
+        a: i32,
+        data: &mut [T],
+    ) -> Result<()> {
+        self.golist(base, labels, index)?.array_read(a, data)
+    }
+
     // https://cgns.github.io/CGNS_docs_current/sids/timedep.html
     pub fn ziter_write(&mut self, base: Base, zone: Zone, zone_iter_name: &str) -> Result<()> {
         let _l = CGNS_MUTEX.lock().unwrap();
-        let zone_iter_name = CString::new(zone_iter_name).unwrap();
+        let zone_iter_name = cstring(zone_iter_name)?;
         let e = unsafe { cg_ziter_write(self.0, base.0, zone.0, zone_iter_name.as_ptr()) };
         if e == 0 {
             Ok(())
@@ -210,7 +411,7 @@ impl File {
     // https://cgns.github.io/CGNS_docs_current/midlevel/structural.html
     pub fn base_write(&mut self, basename: &str, cell_dim: i32, phys_dim: i32) -> Result<Base> {
         let _l = CGNS_MUTEX.lock().unwrap();
-        let basename = CString::new(basename).unwrap();
+        let basename = cstring(basename)?;
         let mut b: i32 = 0;
         let e = unsafe { cg_base_write(self.0, basename.as_ptr(), cell_dim, phys_dim, &mut b) };
         if e == 0 {
@@ -219,25 +420,56 @@ impl File {
             Err(e.into())
         }
     }
+    // `size` holds one `[vertex, cell, boundary]` row per index dimension: a single row for
+    // `Unstructured` zones, or `cell_dim` rows (I, J, K, ...) for `Structured` zones, per the
+    // CGNS SIDS zone size rule.
     pub fn zone_write(
         &mut self,
         base: Base,
         zonename: &str,
-        vertex_size: i32,
-        cell_size: i32,
-        boundary_size: i32,
+        zone_type: ZoneType_t::Type,
+        size: &[[i32; 3]],
     ) -> Result<Zone> {
         let _l = CGNS_MUTEX.lock().unwrap();
-        let zonename = CString::new(zonename).unwrap();
+        let zonename = cstring(zonename)?;
         let mut z: i32 = 0;
-        let size = [vertex_size, cell_size, boundary_size];
+        let mut cell_dim: i32 = 0;
+        let mut phys_dim: i32 = 0;
+        let mut basename = [0_u8; 33];
+        let e = unsafe {
+            cg_base_read(
+                self.0,
+                base.0,
+                basename.as_mut_ptr().cast(),
+                &mut cell_dim,
+                &mut phys_dim,
+            )
+        };
+        if e != 0 {
+            return Err(e.into());
+        }
+        let expected_rows = match zone_type {
+            ZoneType_t::Structured => cell_dim as usize,
+            _ => 1,
+        };
+        assert_eq!(
+            size.len(),
+            expected_rows,
+            "size must have {expected_rows} row(s) for this zone type, got {}",
+            size.len()
+        );
+        // CGNS stores the size with unit stride along the index dimension: all vertex
+        // counts first, then all cell counts, then all boundary counts.
+        let size: Vec<i32> = (0..3)
+            .flat_map(|k| size.iter().map(move |row| row[k]))
+            .collect();
         let e = unsafe {
             cg_zone_write(
                 self.0,
                 base.0,
                 zonename.as_ptr(),
                 size.as_ptr(),
-                Unstructured,
+                zone_type,
                 &mut z,
             )
         };
@@ -249,22 +481,22 @@ impl File {
     }
 
     // https://cgns.github.io/CGNS_docs_current/midlevel/grid.html
-    pub fn coord_write(
+    pub fn coord_write<T: CgnsDataType>(
         &mut self,
         base: Base,
         zone: Zone,
         coordname: &str,
-        coord: &[f64],
+        coord: &[T],
     ) -> Result<()> {
         let _l = CGNS_MUTEX.lock().unwrap();
-        let coordname = CString::new(coordname).unwrap();
+        let coordname = cstring(coordname)?;
         let mut c = 0;
         let e = unsafe {
             cg_coord_write(
                 self.0,
                 base.0,
                 zone.0,
-                RealDouble,
+                T::SYS,
                 coordname.as_ptr(),
                 coord.as_ptr().cast::<c_void>(),
                 &mut c,
@@ -277,8 +509,17 @@ impl File {
         }
     }
 
-    pub fn zone_read(&self, base: Base, zone: Zone) -> Result<(String, Vec<i32>)> {
-        let mut v = Vec::with_capacity(3);
+    // `index_dim` is the zone's index dimension (1 for an unstructured zone, or the base's
+    // `cell_dim` for a structured zone) and determines how many `[vertex, cell, boundary]`
+    // rows are returned.
+    pub fn zone_read(
+        &self,
+        base: Base,
+        zone: Zone,
+        index_dim: i32,
+    ) -> Result<(String, ZoneType_t::Type, Vec<[i32; 3]>)> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let mut v = vec![0_i32; 3 * index_dim as usize];
         let mut buf = [0_u8; 64];
         let err = unsafe {
             cg_zone_read(
@@ -289,8 +530,15 @@ impl File {
                 v.as_mut_ptr(),
             )
         };
+        if err != 0 {
+            return Err(err.into());
+        }
+        let mut zone_type = ZoneType_t::Unstructured;
+        let err = unsafe { cg_zone_type(self.0, base.0, zone.0, &mut zone_type) };
         if err == 0 {
-            Ok((raw_to_string(&buf), v))
+            let n = index_dim as usize;
+            let size = (0..n).map(|d| [v[d], v[n + d], v[2 * n + d]]).collect();
+            Ok((raw_to_string(&buf), zone_type, size))
         } else {
             Err(err.into())
         }
@@ -316,23 +564,23 @@ impl File {
         }
     }
 
-    pub fn coord_read(
+    pub fn coord_read<T: CgnsDataType>(
         &self,
         base: Base,
         zone: Zone,
         coordname: &str,
         range_min: i32,
         range_max: i32,
-        coord_array: &mut [f64],
+        coord_array: &mut [T],
     ) -> Result<()> {
-        let p = CString::new(coordname).unwrap();
+        let p = cstring(coordname)?;
         let err = unsafe {
             cg_coord_read(
                 self.0,
                 base.0,
                 zone.0,
                 p.as_ptr(),
-                RealDouble,
+                T::SYS,
                 &range_min,
                 &range_max,
                 coord_array.as_mut_ptr().cast(),
@@ -353,7 +601,7 @@ impl File {
         elements: &[i32],
     ) -> Result<()> {
         let _l = CGNS_MUTEX.lock().unwrap();
-        let section_name = CString::new(args.section_name.clone()).unwrap();
+        let section_name = cstring(&args.section_name)?;
         let mut c = 0;
         let e = unsafe {
             cg_section_write(
@@ -430,6 +678,151 @@ impl File {
             Err(e.into())
         }
     }
+
+    // https://cgns.github.io/CGNS_docs_current/midlevel/solution.html
+    pub fn sol_write(
+        &mut self,
+        base: Base,
+        zone: Zone,
+        solname: &str,
+        location: GridLocation_t::Type,
+    ) -> Result<i32> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let solname = cstring(solname)?;
+        let mut s: i32 = 0;
+        let e =
+            unsafe { cg_sol_write(self.0, base.0, zone.0, solname.as_ptr(), location, &mut s) };
+        if e == 0 {
+            Ok(s)
+        } else {
+            Err(e.into())
+        }
+    }
+
+    pub fn sol_info(&self, base: Base, zone: Zone, sol: i32) -> Result<SolutionInfo> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let mut location = GridLocation_t::Vertex;
+        let mut raw_name = [0_u8; 64];
+        let e = unsafe {
+            cg_sol_info(
+                self.0,
+                base.0,
+                zone.0,
+                sol,
+                raw_name.as_mut_ptr().cast(),
+                &mut location,
+            )
+        };
+        if e == 0 {
+            Ok(SolutionInfo {
+                name: raw_to_string(&raw_name),
+                location,
+            })
+        } else {
+            Err(e.into())
+        }
+    }
+
+    pub fn nsols(&self, base: Base, zone: Zone) -> Result<i32> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let mut n = 0;
+        let e = unsafe { cg_nsols(self.0, base.0, zone.0, &mut n) };
+        if e == 0 {
+            Ok(n)
+        } else {
+            Err(e.into())
+        }
+    }
+
+    pub fn field_write<T: CgnsDataType>(
+        &mut self,
+        base: Base,
+        zone: Zone,
+        sol: i32,
+        fieldname: &str,
+        data: &[T],
+    ) -> Result<i32> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let fieldname = cstring(fieldname)?;
+        let mut f: i32 = 0;
+        let e = unsafe {
+            cg_field_write(
+                self.0,
+                base.0,
+                zone.0,
+                sol,
+                T::SYS,
+                fieldname.as_ptr(),
+                data.as_ptr().cast::<c_void>(),
+                &mut f,
+            )
+        };
+        if e == 0 {
+            Ok(f)
+        } else {
+            Err(e.into())
+        }
+    }
+
+    pub fn field_info(
+        &self,
+        base: Base,
+        zone: Zone,
+        sol: i32,
+        field: i32,
+    ) -> Result<(DataType_t::Type, String)> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let mut datatype = DataType_t::Integer;
+        let mut raw_name = [0_u8; 64];
+        let e = unsafe {
+            cg_field_info(
+                self.0,
+                base.0,
+                zone.0,
+                sol,
+                field,
+                &mut datatype,
+                raw_name.as_mut_ptr().cast(),
+            )
+        };
+        if e == 0 {
+            Ok((datatype, raw_to_string(&raw_name)))
+        } else {
+            Err(e.into())
+        }
+    }
+
+    pub fn field_read<T: CgnsDataType>(
+        &self,
+        base: Base,
+        zone: Zone,
+        sol: i32,
+        fieldname: &str,
+        range_min: i32,
+        range_max: i32,
+        field: &mut [T],
+    ) -> Result<()> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let fieldname = cstring(fieldname)?;
+        let e = unsafe {
+            cg_field_read(
+                self.0,
+                base.0,
+                zone.0,
+                sol,
+                fieldname.as_ptr(),
+                T::SYS,
+                &range_min,
+                &range_max,
+                field.as_mut_ptr().cast::<c_void>(),
+            )
+        };
+        if e == 0 {
+            Ok(())
+        } else {
+            Err(e.into())
+        }
+    }
 }
 
 impl Drop for File {
@@ -0,0 +1,267 @@
+//! Generic, label-driven traversal of a CGNS node tree, independent of the
+//! semantic (Base/Zone/Section/...) wrappers in the rest of the crate.
+//!
+//! This walks the low-level `cgio` node graph that every mid-level CGNS
+//! object sits on top of, so it can enumerate *any* node — not just the
+//! handful of node types `File` has dedicated methods for. The resulting
+//! [`Node`] tree is plain data (`serde::Serialize`/`Deserialize`), so it can
+//! be dumped to JSON/XML and diffed, checked into version control, or used
+//! to author a CGNS file from scratch via [`File::restore`].
+
+use cgns_sys::{
+    cg_get_cgio, cg_root_id, cgio_children_ids, cgio_get_data_type, cgio_get_dimensions,
+    cgio_get_label, cgio_get_name, cgio_number_children, cgio_read_all_data_type,
+};
+
+use crate::{cstring, raw_to_string, Base, Error, File, Result, Zone, ZoneType_t, CGNS_MUTEX};
+
+/// The raw payload of a [`Node`], keyed by the CGNS two-character data type
+/// code (`I4`, `I8`, `R4`, `R8`, `C1`) or `MT` for a purely structural node.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum NodeData {
+    I4(Vec<i32>),
+    I8(Vec<i64>),
+    R4(Vec<f32>),
+    R8(Vec<f64>),
+    /// CGNS stores names/strings as a `C1` char array rather than a
+    /// CGNS_ENUMV string type, so this is the decoded text, not raw bytes.
+    C1(String),
+    /// `MT` ("no data"): a purely structural node, e.g. most containers.
+    /// Nodes of any other data type (`U4`, `B1`, `LK`, ...) are also dumped
+    /// as `None`; their data is not read.
+    None,
+}
+
+/// One node of a CGNS tree: its label (e.g. `"Zone_t"`), its name, the
+/// dimensions of `data` as stored on disk, and its children in file order.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Node {
+    pub label: String,
+    pub name: String,
+    pub dims: Vec<i32>,
+    pub data: NodeData,
+    pub children: Vec<Node>,
+}
+
+fn read_data(cgio_num: i32, id: f64, data_type: &str, dims: &[i32]) -> Result<NodeData> {
+    let count = dims.iter().product::<i32>().max(0) as usize;
+    let data_type_c = cstring(data_type)?;
+    macro_rules! read_typed {
+        ($variant:ident, $elem:ty) => {{
+            let mut v = vec![<$elem>::default(); count];
+            let e = unsafe {
+                cgio_read_all_data_type(
+                    cgio_num,
+                    id,
+                    data_type_c.as_ptr(),
+                    v.as_mut_ptr().cast(),
+                )
+            };
+            if e == 0 {
+                Ok(NodeData::$variant(v))
+            } else {
+                Err(e.into())
+            }
+        }};
+    }
+    match data_type {
+        "MT" => Ok(NodeData::None),
+        "I4" => read_typed!(I4, i32),
+        "I8" => read_typed!(I8, i64),
+        "R4" => read_typed!(R4, f32),
+        "R8" => read_typed!(R8, f64),
+        "C1" => {
+            let mut buf = vec![0_u8; count];
+            let e = unsafe {
+                cgio_read_all_data_type(cgio_num, id, data_type_c.as_ptr(), buf.as_mut_ptr().cast())
+            };
+            if e != 0 {
+                return Err(e.into());
+            }
+            let end = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+            Ok(NodeData::C1(String::from_utf8_lossy(&buf[..end]).into_owned()))
+        }
+        _ => Ok(NodeData::None),
+    }
+}
+
+fn label_of(cgio_num: i32, id: f64) -> Result<String> {
+    let mut raw_label = [0_u8; 33];
+    let e = unsafe { cgio_get_label(cgio_num, id, raw_label.as_mut_ptr().cast()) };
+    if e == 0 {
+        Ok(raw_to_string(&raw_label))
+    } else {
+        Err(e.into())
+    }
+}
+
+/// Find the `nth` (1-based) child of `parent_id` whose label is `label`.
+///
+/// `cg_open` always writes a `CGNSLibraryVersion_t` node as the root's first
+/// child before any `CGNSBase_t` node, so a CGNS base/zone number is not the
+/// same as its raw position among the node's children — it has to be found
+/// by filtering on label first.
+fn nth_child_with_label(cgio_num: i32, parent_id: f64, label: &str, nth: i32) -> Result<f64> {
+    let found = children_ids(cgio_num, parent_id)?
+        .into_iter()
+        .filter_map(|id| match label_of(cgio_num, id) {
+            Ok(l) if l == label => Some(Ok(id)),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+        .nth((nth - 1) as usize);
+    match found {
+        Some(r) => r,
+        None => Err(Error::Cgns {
+            code: 0,
+            message: format!("no {label} node at index {nth}"),
+        }),
+    }
+}
+
+fn children_ids(cgio_num: i32, parent_id: f64) -> Result<Vec<f64>> {
+    let mut num_children: i32 = 0;
+    let e = unsafe { cgio_number_children(cgio_num, parent_id, &mut num_children) };
+    if e != 0 {
+        return Err(e.into());
+    }
+    let mut ids = vec![0.0_f64; num_children as usize];
+    let mut num_ret: i32 = 0;
+    if num_children > 0 {
+        let e = unsafe {
+            cgio_children_ids(cgio_num, parent_id, 1, num_children, &mut num_ret, ids.as_mut_ptr())
+        };
+        if e != 0 {
+            return Err(e.into());
+        }
+    }
+    Ok(ids)
+}
+
+fn dump_node(cgio_num: i32, id: f64) -> Result<Node> {
+    let mut raw_name = [0_u8; 33];
+    let mut raw_label = [0_u8; 33];
+    let mut raw_data_type = [0_u8; 3];
+    let mut ndim: i32 = 0;
+    let mut raw_dims = [0_i32; 12];
+    unsafe {
+        let e = cgio_get_name(cgio_num, id, raw_name.as_mut_ptr().cast());
+        if e != 0 {
+            return Err(e.into());
+        }
+        let e = cgio_get_label(cgio_num, id, raw_label.as_mut_ptr().cast());
+        if e != 0 {
+            return Err(e.into());
+        }
+        let e = cgio_get_data_type(cgio_num, id, raw_data_type.as_mut_ptr().cast());
+        if e != 0 {
+            return Err(e.into());
+        }
+        let e = cgio_get_dimensions(cgio_num, id, &mut ndim, raw_dims.as_mut_ptr());
+        if e != 0 {
+            return Err(e.into());
+        }
+    }
+    let data_type = std::str::from_utf8(&raw_data_type[0..2]).unwrap_or("MT");
+    let dims = raw_dims[0..ndim as usize].to_vec();
+    let data = read_data(cgio_num, id, data_type, &dims)?;
+    let children = children_ids(cgio_num, id)?
+        .into_iter()
+        .map(|child_id| dump_node(cgio_num, child_id))
+        .collect::<Result<_>>()?;
+    Ok(Node {
+        label: raw_to_string(&raw_label),
+        name: raw_to_string(&raw_name),
+        dims,
+        data,
+        children,
+    })
+}
+
+fn parse_zone_type(s: &str) -> ZoneType_t::Type {
+    match s {
+        "Structured" => ZoneType_t::Structured,
+        _ => ZoneType_t::Unstructured,
+    }
+}
+
+impl File {
+    /// Recursively dump `base` (and everything under it) into an in-memory,
+    /// serializable tree.
+    pub fn dump(&self, base: Base) -> Result<Node> {
+        let _l = CGNS_MUTEX.lock().unwrap();
+        let mut cgio_num: i32 = 0;
+        let e = unsafe { cg_get_cgio(self.0, &mut cgio_num) };
+        if e != 0 {
+            return Err(e.into());
+        }
+        let mut root_id: f64 = 0.0;
+        let e = unsafe { cg_root_id(self.0, &mut root_id) };
+        if e != 0 {
+            return Err(e.into());
+        }
+        let base_id = nth_child_with_label(cgio_num, root_id, "CGNSBase_t", base.0)?;
+        dump_node(cgio_num, base_id)
+    }
+
+    /// Recreate a base (and its zones and data arrays) from a tree produced
+    /// by [`File::dump`].
+    ///
+    /// Only the levels `File::dump` is most useful for round-tripping are
+    /// reconstructed: `CGNSBase_t`, its `Zone_t` children and their direct
+    /// `DataArray_t` children. Deeper semantic containers (`GridCoordinates_t`,
+    /// `FlowSolution_t`, element sections, ...) are not recreated here — use
+    /// the dedicated `coord_write`/`section_write`/`field_write` methods for
+    /// those, as `restore` is meant for lightweight config-authored zones.
+    pub fn restore(&mut self, node: &Node) -> Result<Base> {
+        let [cell_dim, phys_dim] = match &node.data {
+            NodeData::I4(v) if v.len() == 2 => [v[0], v[1]],
+            _ => {
+                return Err(Error::Cgns {
+                    code: 0,
+                    message: format!("{} is not a valid CGNSBase_t node", node.label),
+                })
+            }
+        };
+        let base = self.base_write(&node.name, cell_dim, phys_dim)?;
+        for zone_node in node.children.iter().filter(|c| c.label == "Zone_t") {
+            self.restore_zone(base, zone_node)?;
+        }
+        Ok(base)
+    }
+
+    fn restore_zone(&mut self, base: Base, node: &Node) -> Result<Zone> {
+        let size: Vec<[i32; 3]> = match &node.data {
+            NodeData::I4(v) => {
+                let n = v.len() / 3;
+                (0..n).map(|d| [v[d], v[n + d], v[2 * n + d]]).collect()
+            }
+            _ => Vec::new(),
+        };
+        let zone_type = node
+            .children
+            .iter()
+            .find(|c| c.label == "ZoneType_t")
+            .and_then(|c| match &c.data {
+                NodeData::C1(s) => Some(parse_zone_type(s)),
+                _ => None,
+            })
+            .unwrap_or(ZoneType_t::Unstructured);
+        let zone = self.zone_write(base, &node.name, zone_type, &size)?;
+        for array_node in node.children.iter().filter(|c| c.label == "DataArray_t") {
+            self.restore_array(base, zone, array_node)?;
+        }
+        Ok(zone)
+    }
+
+    fn restore_array(&mut self, base: Base, zone: Zone, node: &Node) -> Result<()> {
+        let ctx = self.golist(base, &["Zone_t"], &[zone.0])?;
+        match &node.data {
+            NodeData::I4(v) => ctx.array_write(&node.name, &node.dims, v),
+            NodeData::I8(v) => ctx.array_write(&node.name, &node.dims, v),
+            NodeData::R4(v) => ctx.array_write(&node.name, &node.dims, v),
+            NodeData::R8(v) => ctx.array_write(&node.name, &node.dims, v),
+            NodeData::C1(_) | NodeData::None => Ok(()),
+        }
+    }
+}